@@ -1,7 +1,6 @@
 use mccm::{MnistNetwork, MNIST_AREA};
 use mnist::{Mnist, MnistBuilder};
-use rand::Rng;
-use crate::neurology::CompAENetwork;
+use crate::neurology::{CompAENetwork, InitScheme};
 
 mod neurology;
 
@@ -12,14 +11,22 @@ const EPOCHS: usize = 10;
 const MIN_INIT_WEIGHT: f32 = 0.0;
 const MAX_INIT_WEIGHT: f32 = 1.0 / NUM_NEURONS as f32;
 
+const INIT_SCHEME: InitScheme = InitScheme::Uniform {
+    min: MIN_INIT_WEIGHT,
+    max: MAX_INIT_WEIGHT,
+};
+
 const TRAINING_SET_LENGTH: u32 = 10000;
 const TEST_SET_LENGTH: u32 = 10000;
 
 const LOGGER_ON: bool = true;
 
-fn generate_weight() -> f32 {
-    rand::thread_rng().gen_range(MIN_INIT_WEIGHT, MAX_INIT_WEIGHT)
-}
+const USE_ADAM: bool = false;
+
+const WEIGHT_DECAY: f32 = 0.0;
+const MAX_NORM: Option<f32> = None;
+
+const BATCH_SIZE: usize = 1;
 
 fn main() {
     let Mnist {
@@ -38,10 +45,25 @@ fn main() {
     let train_img: Vec<f32> = trn_img.iter().map(|val| *val as f32 / 255.0).collect();
     let test_img: Vec<f32> = tst_img.iter().map(|val| *val as f32 / 255.0).collect();
 
-    let mut network = CompAENetwork::new(LEARNING_CONST, NUM_NEURONS, MNIST_AREA, generate_weight);
+    let mut network = CompAENetwork::new(
+        LEARNING_CONST,
+        NUM_NEURONS,
+        MNIST_AREA,
+        USE_ADAM,
+        WEIGHT_DECAY,
+        MAX_NORM,
+        INIT_SCHEME,
+        BATCH_SIZE,
+        TRAINING_SET_LENGTH as usize,
+    );
 
     let accuracy = network.take_metric(train_img, trn_lbl, EPOCHS, test_img, tst_lbl, LOGGER_ON);
 
+    // `CompAENetwork::perform_adjustment` already flushes any partial
+    // mini-batch at every epoch boundary, the last one included, so this
+    // only commits something if a partial batch is still pending.
+    network.flush_batch();
+
     println!("Model accuracy: {}", accuracy);
 
     network.serialize();
@@ -5,6 +5,60 @@ use std::path::Path;
 use std::fs::File;
 use std::io::Write;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+use std::io::Read;
+
+/// Strategy used to draw a neuron's initial synapse weights
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum InitScheme {
+    /// Uniform draw from `[min, max)` (`min` must be less than `max`), e.g. the
+    /// original `[0, 1/NUM_NEURONS)` scheme
+    Uniform { min: f32, max: f32 },
+    /// Uniform draw from `[-limit, limit]` with `limit = sqrt(6 / (fan_in + fan_out))`
+    XavierUniform,
+    /// Gaussian draw with stddev `sqrt(2 / (fan_in + fan_out))`
+    XavierNormal,
+    /// `XavierUniform`, but folded to `[0, limit]` via absolute value so weights
+    /// start compatible with the non-negativity clamp in the learning phase
+    HalfXavierUniform,
+    /// `XavierNormal`, but folded to non-negative via absolute value
+    HalfXavierNormal,
+}
+
+impl InitScheme {
+    fn sample(&self, fan_in: usize, fan_out: usize) -> f32 {
+        let mut rng = rand::thread_rng();
+
+        match self {
+            InitScheme::Uniform { min, max } => rng.gen_range(*min, *max),
+            InitScheme::XavierUniform => {
+                let limit = (6.0 / (fan_in + fan_out) as f32).sqrt();
+                rng.gen_range(-limit, limit)
+            }
+            InitScheme::XavierNormal => {
+                let stddev = (2.0 / (fan_in + fan_out) as f32).sqrt();
+                Self::sample_gaussian(&mut rng) * stddev
+            }
+            InitScheme::HalfXavierUniform => {
+                let limit = (6.0 / (fan_in + fan_out) as f32).sqrt();
+                rng.gen_range(-limit, limit).abs()
+            }
+            InitScheme::HalfXavierNormal => {
+                let stddev = (2.0 / (fan_in + fan_out) as f32).sqrt();
+                (Self::sample_gaussian(&mut rng) * stddev).abs()
+            }
+        }
+    }
+
+    /// Standard-normal sample via the Box-Muller transform
+    fn sample_gaussian(rng: &mut impl Rng) -> f32 {
+        let u1: f32 = rng.gen_range(f32::EPSILON, 1.0);
+        let u2: f32 = rng.gen_range(0.0, 1.0);
+
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
 
 pub struct NeuronicInput {
     measure: Cell<f32>,
@@ -85,11 +139,26 @@ impl WeightHolder {
     }
 }
 
+/// Adam hyperparameters, fixed to their conventional defaults
+const ADAM_BETA1: f32 = 0.9;
+const ADAM_BETA2: f32 = 0.999;
+const ADAM_EPSILON: f32 = 1e-8;
+
 pub struct CompAENeuron {
     name: String,
     learning_constant: f32,
+    use_adam: bool,
+    /// L2 weight decay coefficient; `0.0` disables it
+    weight_decay: f32,
+    /// Max-norm constraint applied to the full weight vector; `None` disables it
+    max_norm: Option<f32>,
     inputs: Vec<Rc<NeuronicInput>>,
     weights: Vec<Cell<f32>>,
+    /// Per-weight delta accumulated across the current mini-batch, committed
+    /// (averaged, then clamped) once the batch boundary is reached
+    pending_deltas: Vec<Cell<f32>>,
+    m: Vec<Cell<f32>>,
+    v: Vec<Cell<f32>>,
     weight_holder: Rc<WeightHolder>,
     current_em: Cell<f32>,
 }
@@ -98,24 +167,80 @@ impl CompAENeuron {
     pub fn new(
         name: String,
         learning_constant: f32,
-        gen_weight: fn() -> f32,
+        use_adam: bool,
+        weight_decay: f32,
+        max_norm: Option<f32>,
+        init_scheme: InitScheme,
+        num_neurons: usize,
         inputs: Vec<Rc<NeuronicInput>>,
         weight_holder: Rc<WeightHolder>,
     ) -> CompAENeuron {
         let weights = (0..inputs.len())
-            .map(|_| Cell::new(gen_weight()))
+            .map(|_| Cell::new(init_scheme.sample(inputs.len(), num_neurons)))
             .collect::<Vec<Cell<f32>>>();
+        let pending_deltas = (0..inputs.len()).map(|_| Cell::new(0.0)).collect::<Vec<Cell<f32>>>();
+        let m = (0..inputs.len()).map(|_| Cell::new(0.0)).collect::<Vec<Cell<f32>>>();
+        let v = (0..inputs.len()).map(|_| Cell::new(0.0)).collect::<Vec<Cell<f32>>>();
+
+        CompAENeuron {
+            name,
+            learning_constant,
+            use_adam,
+            weight_decay,
+            max_norm,
+            inputs,
+            weights,
+            pending_deltas,
+            m,
+            v,
+            weight_holder,
+            current_em: Cell::new(0.0),
+        }
+    }
+
+    /// Rebuilds a neuron from a previously saved weight vector, bypassing
+    /// `InitScheme` sampling entirely. Used when deserializing a checkpoint.
+    /// The optimizer/regularizer config is passed in (rather than reset to
+    /// defaults) so resumed training matches the original run.
+    pub fn from_weights(
+        name: String,
+        learning_constant: f32,
+        use_adam: bool,
+        weight_decay: f32,
+        max_norm: Option<f32>,
+        weights: Vec<f32>,
+        inputs: Vec<Rc<NeuronicInput>>,
+        weight_holder: Rc<WeightHolder>,
+    ) -> CompAENeuron {
+        let pending_deltas = (0..weights.len()).map(|_| Cell::new(0.0)).collect::<Vec<Cell<f32>>>();
+        let m = (0..weights.len()).map(|_| Cell::new(0.0)).collect::<Vec<Cell<f32>>>();
+        let v = (0..weights.len()).map(|_| Cell::new(0.0)).collect::<Vec<Cell<f32>>>();
+        let weights = weights.into_iter().map(Cell::new).collect::<Vec<Cell<f32>>>();
 
         CompAENeuron {
             name,
             learning_constant,
+            use_adam,
+            weight_decay,
+            max_norm,
             inputs,
             weights,
+            pending_deltas,
+            m,
+            v,
             weight_holder,
             current_em: Cell::new(0.0),
         }
     }
 
+    pub fn get_weights(&self) -> Vec<f32> {
+        self.weights.iter().map(|w| w.get()).collect()
+    }
+
+    pub fn get_current_em(&self) -> f32 {
+        self.current_em.get()
+    }
+
     pub fn run_prediction_phase(&self) {
         let em = self.compute_em();
         self.current_em.replace(em);
@@ -126,15 +251,88 @@ impl CompAENeuron {
         }
     }
 
-    pub fn run_learning_phase(&self) {
+    /// Accumulates this sample's contribution to the current mini-batch into
+    /// `pending_deltas`; weights aren't touched until `commit_batch` runs.
+    /// `t` is the network-wide Adam timestep, shared across every neuron so
+    /// bias correction stays consistent no matter how many neurons there are.
+    /// It's ignored when this neuron wasn't constructed with `use_adam`.
+    pub fn run_learning_phase(&self, t: u32) {
+        if self.use_adam {
+            self.accumulate_adam(t);
+        } else {
+            self.accumulate_sgd();
+        }
+    }
+
+    fn accumulate_sgd(&self) {
         let adjustment_size = (self.current_em.get() / self.weight_holder.get_total_weight())
             * self.learning_constant;
 
-        for (input, weight) in self.inputs.iter().zip(self.weights.iter()) {
-            weight.replace(weight.get() + (-1.0 * input.get_reconstruction_error() * adjustment_size));
+        for (input, delta) in self.inputs.iter().zip(self.pending_deltas.iter()) {
+            delta.replace(delta.get() + input.get_reconstruction_error() * adjustment_size);
+        }
+    }
 
-            if weight.get() < 0.0 {
-                weight.replace(0.0);
+    fn accumulate_adam(&self, t: u32) {
+        let em_share = self.current_em.get() / self.weight_holder.get_total_weight();
+        let bias_correction1 = 1.0 - ADAM_BETA1.powi(t as i32);
+        let bias_correction2 = 1.0 - ADAM_BETA2.powi(t as i32);
+
+        for ((input, delta), (m, v)) in self
+            .inputs
+            .iter()
+            .zip(self.pending_deltas.iter())
+            .zip(self.m.iter().zip(self.v.iter()))
+        {
+            let g = input.get_reconstruction_error() * em_share;
+
+            m.replace(ADAM_BETA1 * m.get() + (1.0 - ADAM_BETA1) * g);
+            v.replace(ADAM_BETA2 * v.get() + (1.0 - ADAM_BETA2) * g * g);
+
+            let m_hat = m.get() / bias_correction1;
+            let v_hat = v.get() / bias_correction2;
+
+            delta.replace(delta.get() + self.learning_constant * m_hat / (v_hat.sqrt() + ADAM_EPSILON));
+        }
+    }
+
+    /// Applies the averaged mini-batch delta to every weight, then the
+    /// regularizers, and resets the accumulators for the next batch.
+    /// `batch_size` is the number of samples actually accumulated, which may
+    /// be smaller than the configured batch size for a flushed partial batch.
+    pub fn commit_batch(&self, batch_size: usize) {
+        for (weight, delta) in self.weights.iter().zip(self.pending_deltas.iter()) {
+            weight.replace(weight.get() - delta.get() / batch_size as f32);
+            delta.replace(0.0);
+            self.clamp_weight(weight);
+        }
+
+        self.apply_max_norm();
+    }
+
+    /// Applies L2 weight decay and the non-negativity floor to a single weight
+    fn clamp_weight(&self, weight: &Cell<f32>) {
+        weight.replace(weight.get() - self.weight_decay * weight.get());
+
+        if weight.get() < 0.0 {
+            weight.replace(0.0);
+        }
+    }
+
+    /// Rescales the full weight vector down to `max_norm` if it's been exceeded
+    fn apply_max_norm(&self) {
+        let max_norm = match self.max_norm {
+            Some(max_norm) => max_norm,
+            None => return,
+        };
+
+        let norm = self.weights.iter().map(|w| w.get() * w.get()).sum::<f32>().sqrt();
+
+        if norm > max_norm {
+            let scale = max_norm / norm;
+
+            for weight in self.weights.iter() {
+                weight.replace(weight.get() * scale);
             }
         }
     }
@@ -177,14 +375,357 @@ impl MnistNeuron for CompAENeuron {
     }
 }
 
+/// One filter of a convolutional bank: a single `kernel_size x kernel_size`
+/// kernel, shared across every stride-spaced position of a `side x side` grid.
+/// `CompAENetwork::new_conv` builds `num_filters` of these, each with its own kernel.
+/// Each position reconstructs its own local patch and competes independently,
+/// and the learning phase averages the reconstruction-error gradient across
+/// every position before updating the one shared kernel.
+///
+/// With `stride < kernel_size`, receptive fields overlap, so a pixel near the
+/// center of the grid is written to by more windows than a corner pixel.
+/// `run_prediction_phase` divides each window's contribution to a pixel by
+/// that pixel's overlap count, so the shared per-pixel reconstruction is a
+/// true average over the windows covering it rather than a position-biased
+/// sum — matching a per-window local reconstruction for any stride.
+pub struct ConvFilter {
+    name: String,
+    learning_constant: f32,
+    kernel_size: usize,
+    stride: usize,
+    side: usize,
+    weights: Vec<Cell<f32>>,
+    inputs: Vec<Rc<NeuronicInput>>,
+    weight_holder: Rc<WeightHolder>,
+    /// Number of receptive-field windows covering each `inputs` pixel
+    overlap_counts: Vec<f32>,
+    /// `em` for each output position, laid out row-major over the output grid
+    position_ems: Vec<Cell<f32>>,
+    /// Mean of `position_ems`, exposed through `MnistNeuron::compute_em`
+    current_em: Cell<f32>,
+}
+
+impl ConvFilter {
+    pub fn new(
+        name: String,
+        learning_constant: f32,
+        kernel_size: usize,
+        stride: usize,
+        side: usize,
+        init_scheme: InitScheme,
+        num_filters: usize,
+        inputs: Vec<Rc<NeuronicInput>>,
+        weight_holder: Rc<WeightHolder>,
+    ) -> ConvFilter {
+        let kernel_area = kernel_size * kernel_size;
+        let weights = (0..kernel_area)
+            .map(|_| Cell::new(init_scheme.sample(kernel_area, num_filters)))
+            .collect::<Vec<Cell<f32>>>();
+
+        let output_side = (side - kernel_size) / stride + 1;
+        let position_ems = (0..output_side * output_side)
+            .map(|_| Cell::new(0.0))
+            .collect::<Vec<Cell<f32>>>();
+
+        let overlap_counts = Self::compute_overlap_counts(side, kernel_size, stride, output_side);
+
+        ConvFilter {
+            name,
+            learning_constant,
+            kernel_size,
+            stride,
+            side,
+            weights,
+            inputs,
+            weight_holder,
+            overlap_counts,
+            position_ems,
+            current_em: Cell::new(0.0),
+        }
+    }
+
+    fn output_side(&self) -> usize {
+        (self.side - self.kernel_size) / self.stride + 1
+    }
+
+    /// Flat `inputs` indices covered by the receptive field at output position `(ox, oy)`
+    fn window_indices(&self, ox: usize, oy: usize) -> Vec<usize> {
+        let base_x = ox * self.stride;
+        let base_y = oy * self.stride;
+
+        (0..self.kernel_size)
+            .flat_map(|ky| (0..self.kernel_size).map(move |kx| (kx, ky)))
+            .map(|(kx, ky)| (base_y + ky) * self.side + (base_x + kx))
+            .collect()
+    }
+
+    /// Number of windows covering each of the `side * side` input pixels,
+    /// fixed by the geometry alone (same for every sample), so it's computed
+    /// once at construction rather than on every `run_prediction_phase` call.
+    fn compute_overlap_counts(side: usize, kernel_size: usize, stride: usize, output_side: usize) -> Vec<f32> {
+        let mut counts = vec![0.0f32; side * side];
+
+        for oy in 0..output_side {
+            for ox in 0..output_side {
+                let base_x = ox * stride;
+                let base_y = oy * stride;
+
+                for ky in 0..kernel_size {
+                    for kx in 0..kernel_size {
+                        counts[(base_y + ky) * side + (base_x + kx)] += 1.0;
+                    }
+                }
+            }
+        }
+
+        counts
+    }
+
+    pub fn get_weights(&self) -> Vec<f32> {
+        self.weights.iter().map(|w| w.get()).collect()
+    }
+
+    pub fn get_current_em(&self) -> f32 {
+        self.current_em.get()
+    }
+
+    pub fn run_prediction_phase(&self) {
+        let output_side = self.output_side();
+
+        for oy in 0..output_side {
+            for ox in 0..output_side {
+                let indices = self.window_indices(ox, oy);
+
+                let mut total_weight = 0.0;
+                let mut total_weighted_em = 0.0;
+
+                for (weight, &idx) in self.weights.iter().zip(indices.iter()) {
+                    total_weight += weight.get();
+                    total_weighted_em += weight.get() * self.inputs[idx].get_measure();
+                }
+
+                let em = total_weighted_em / total_weight.sqrt();
+                self.position_ems[oy * output_side + ox].replace(em);
+                self.weight_holder.incr_weight(em);
+
+                // Divide by the pixel's overlap count so a pixel covered by
+                // several overlapping windows gets their *average*
+                // reconstruction rather than their sum.
+                for (weight, &idx) in self.weights.iter().zip(indices.iter()) {
+                    let contribution = em * weight.get() / self.overlap_counts[idx];
+                    self.inputs[idx].incr_total_weighted_prediction(contribution);
+                }
+            }
+        }
+
+        let mean_em = self.position_ems.iter().map(|em| em.get()).sum::<f32>() / self.position_ems.len() as f32;
+        self.current_em.replace(mean_em);
+    }
+
+    /// Averages the reconstruction-error gradient across every receptive-field
+    /// position before applying a single SGD update to the shared kernel
+    pub fn run_learning_phase(&self) {
+        let output_side = self.output_side();
+        let mut gradient = vec![0.0f32; self.weights.len()];
+
+        for oy in 0..output_side {
+            for ox in 0..output_side {
+                let indices = self.window_indices(ox, oy);
+                let em = self.position_ems[oy * output_side + ox].get();
+                let adjustment_size = (em / self.weight_holder.get_total_weight()) * self.learning_constant;
+
+                for (g, &idx) in gradient.iter_mut().zip(indices.iter()) {
+                    *g += self.inputs[idx].get_reconstruction_error() * adjustment_size;
+                }
+            }
+        }
+
+        let num_positions = (output_side * output_side) as f32;
+
+        for (weight, g) in self.weights.iter().zip(gradient.iter()) {
+            weight.replace(weight.get() - g / num_positions);
+
+            if weight.get() < 0.0 {
+                weight.replace(0.0);
+            }
+        }
+    }
+
+    pub fn to_serializable(&self) -> Vec<Vec<f32>> {
+        let mut val_matrix = Vec::new();
+
+        for j in 0..self.kernel_size {
+            let mut val_row = Vec::new();
+
+            for i in 0..self.kernel_size {
+                val_row.push(self.weights.get((j * self.kernel_size) + i).unwrap().get());
+            }
+
+            val_matrix.push(val_row);
+        }
+
+        val_matrix
+    }
+}
+
+impl MnistNeuron for ConvFilter {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn compute_em(&self) -> f32 {
+        self.current_em.get()
+    }
+}
+
+/// Holds the network's neurons, either fully connected (`Dense`) or a bank of
+/// shared convolutional kernels (`Conv`). A network is never a mix of the two.
+enum NeuronBank {
+    Dense(Vec<Rc<CompAENeuron>>),
+    Conv(Vec<Rc<ConvFilter>>),
+}
+
+impl NeuronBank {
+    fn len(&self) -> usize {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.len(),
+            NeuronBank::Conv(filters) => filters.len(),
+        }
+    }
+
+    fn run_prediction_phase(&self) {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.iter().for_each(|n| n.run_prediction_phase()),
+            NeuronBank::Conv(filters) => filters.iter().for_each(|f| f.run_prediction_phase()),
+        }
+    }
+
+    fn run_learning_phase(&self, t: u32) {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.iter().for_each(|n| n.run_learning_phase(t)),
+            NeuronBank::Conv(filters) => filters.iter().for_each(|f| f.run_learning_phase()),
+        }
+    }
+
+    fn commit_batch(&self, batch_size: usize) {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.iter().for_each(|n| n.commit_batch(batch_size)),
+            // Convolutional filters apply their (single, averaged) update
+            // directly in `run_learning_phase`; batching isn't supported yet.
+            NeuronBank::Conv(_) => {}
+        }
+    }
+
+    fn to_serializable_all(&self) -> Vec<Vec<Vec<f32>>> {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.iter().map(|n| n.to_serializable()).collect(),
+            NeuronBank::Conv(filters) => filters.iter().map(|f| f.to_serializable()).collect(),
+        }
+    }
+
+    fn get_weights_all(&self) -> Vec<Vec<f32>> {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.iter().map(|n| n.get_weights()).collect(),
+            NeuronBank::Conv(filters) => filters.iter().map(|f| f.get_weights()).collect(),
+        }
+    }
+
+    fn get_current_ems(&self) -> Vec<f32> {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.iter().map(|n| n.get_current_em()).collect(),
+            NeuronBank::Conv(filters) => filters.iter().map(|f| f.get_current_em()).collect(),
+        }
+    }
+
+    fn mnist_neurons(&self) -> Vec<Rc<dyn MnistNeuron>> {
+        match self {
+            NeuronBank::Dense(neurons) => neurons.iter().map(|n| Rc::clone(n) as Rc<dyn MnistNeuron>).collect(),
+            NeuronBank::Conv(filters) => filters.iter().map(|f| Rc::clone(f) as Rc<dyn MnistNeuron>).collect(),
+        }
+    }
+}
+
 pub struct CompAENetwork {
-    neurons: Vec<Rc<CompAENeuron>>,
+    learning_constant: f32,
+    /// Retained (alongside `weight_decay`/`max_norm`/`init_scheme` below) so
+    /// `save`/`load` can restore the exact training configuration.
+    use_adam: bool,
+    weight_decay: f32,
+    max_norm: Option<f32>,
+    init_scheme: InitScheme,
+    neurons: NeuronBank,
     inputs: Vec<Rc<NeuronicInput>>,
     weight_holder: Rc<WeightHolder>,
+    /// Shared Adam timestep, incremented once per learning phase and handed
+    /// to every neuron so their bias correction stays in lock-step.
+    t: Cell<u32>,
+    /// Number of samples accumulated per weight update; `1` reproduces the
+    /// original online (per-sample) learning behavior.
+    batch_size: usize,
+    /// Samples accumulated into the current mini-batch so far
+    batch_count: Cell<usize>,
+    /// Training samples per epoch, so `perform_adjustment` can flush any
+    /// partial mini-batch at the epoch boundary on its own, without the
+    /// `MnistNetwork` driver needing to expose one. `0` disables the
+    /// auto-flush (e.g. for `new_conv`, where mini-batching isn't supported).
+    samples_per_epoch: usize,
+    /// Training samples seen since the last epoch-boundary flush
+    epoch_sample_count: Cell<usize>,
 }
 
+/// Per-neuron results of running a single input through a trained network
+/// without touching its weights
+pub struct InferenceResult {
+    pub reconstruction: Vec<f32>,
+    pub neuron_ems: Vec<f32>,
+    pub winning_neuron: usize,
+}
+
+/// On-disk checkpoint format for `CompAENetwork::save`/`load`. `version` lets
+/// future formats evolve without breaking older checkpoints. Captures the
+/// full training configuration (not just weights) so a loaded network can
+/// resume training and reproduce the original run, not just run inference.
+#[derive(Serialize, Deserialize)]
+struct SerializedNetwork {
+    version: u32,
+    learning_constant: f32,
+    use_adam: bool,
+    weight_decay: f32,
+    max_norm: Option<f32>,
+    init_scheme: InitScheme,
+    batch_size: usize,
+    samples_per_epoch: usize,
+    num_neurons: usize,
+    num_inputs: usize,
+    weights: Vec<Vec<f32>>,
+}
+
+const NETWORK_FORMAT_VERSION: u32 = 1;
+
 impl CompAENetwork {
-    pub fn new(learning_constant: f32, num_neurons: usize, num_inputs: usize, gen_synapse_weight: fn() -> f32) -> CompAENetwork {
+    pub fn new(
+        learning_constant: f32,
+        num_neurons: usize,
+        num_inputs: usize,
+        use_adam: bool,
+        weight_decay: f32,
+        max_norm: Option<f32>,
+        init_scheme: InitScheme,
+        batch_size: usize,
+        samples_per_epoch: usize,
+    ) -> CompAENetwork {
+        // Mini-batching accumulates each sample's raw delta and averages it at
+        // `commit_batch`, but `accumulate_adam` computes a *complete* Adam
+        // step (including the moment-estimate update) per sample; averaging
+        // `batch_size` complete Adam steps together isn't equivalent to one
+        // Adam step on the batch-averaged gradient, and `m`/`v` would also
+        // advance once per sample rather than once per batch. Reject the
+        // combination rather than silently running non-standard Adam.
+        assert!(
+            !(use_adam && batch_size > 1),
+            "CompAENetwork::new: use_adam and batch_size > 1 cannot be combined yet"
+        );
+
         let weight_holder = Rc::new(WeightHolder::new());
 
         // Initialize inputs
@@ -197,7 +738,11 @@ impl CompAENetwork {
                 Rc::new(CompAENeuron::new(
                     i.to_string(),
                     learning_constant,
-                    gen_synapse_weight,
+                    use_adam,
+                    weight_decay,
+                    max_norm,
+                    init_scheme,
+                    num_neurons,
                     inputs.iter().map(|input| Rc::clone(input)).collect(),
                     Rc::clone(&weight_holder),
                 ))
@@ -205,14 +750,95 @@ impl CompAENetwork {
             .collect::<Vec<Rc<CompAENeuron>>>();
 
         CompAENetwork {
-            neurons,
+            learning_constant,
+            use_adam,
+            weight_decay,
+            max_norm,
+            init_scheme,
+            neurons: NeuronBank::Dense(neurons),
+            inputs,
+            weight_holder,
+            t: Cell::new(0),
+            batch_size,
+            batch_count: Cell::new(0),
+            samples_per_epoch,
+            epoch_sample_count: Cell::new(0),
+        }
+    }
+
+    /// Builds a convolutional variant: a bank of `num_filters` neurons, each
+    /// sharing a single `kernel_size x kernel_size` kernel applied at every
+    /// `stride`-spaced position of a `side x side` input grid, rather than
+    /// every neuron being fully connected to all `side * side` inputs.
+    pub fn new_conv(
+        learning_constant: f32,
+        kernel_size: usize,
+        num_filters: usize,
+        stride: usize,
+        side: usize,
+        init_scheme: InitScheme,
+    ) -> CompAENetwork {
+        let weight_holder = Rc::new(WeightHolder::new());
+
+        let inputs = (0..side * side)
+            .map(|_| Rc::new(NeuronicInput::new(Rc::clone(&weight_holder))))
+            .collect::<Vec<Rc<NeuronicInput>>>();
+
+        let filters = (0..num_filters)
+            .map(|i| {
+                Rc::new(ConvFilter::new(
+                    i.to_string(),
+                    learning_constant,
+                    kernel_size,
+                    stride,
+                    side,
+                    init_scheme,
+                    num_filters,
+                    inputs.iter().map(|input| Rc::clone(input)).collect(),
+                    Rc::clone(&weight_holder),
+                ))
+            })
+            .collect::<Vec<Rc<ConvFilter>>>();
+
+        CompAENetwork {
+            learning_constant,
+            // Conv filters don't yet support Adam/decay/max-norm/mini-batching
+            // (see `NeuronBank::commit_batch`), so these stay at their defaults.
+            use_adam: false,
+            weight_decay: 0.0,
+            max_norm: None,
+            init_scheme,
+            neurons: NeuronBank::Conv(filters),
             inputs,
-            weight_holder
+            weight_holder,
+            t: Cell::new(0),
+            batch_size: 1,
+            batch_count: Cell::new(0),
+            samples_per_epoch: 0,
+            epoch_sample_count: Cell::new(0),
+        }
+    }
+
+    /// Applies the accumulated deltas for every neuron's current mini-batch
+    /// and resets the batch counter
+    fn commit_batch(&self) {
+        self.neurons.commit_batch(self.batch_count.get());
+        self.batch_count.replace(0);
+    }
+
+    /// Commits any partial mini-batch immediately, rather than waiting for
+    /// `batch_size` samples to accumulate. Called automatically at every
+    /// epoch boundary, the last one included (see `perform_adjustment`), so a
+    /// partial batch is never carried across epochs; also public so a driver
+    /// can flush on demand (a no-op if nothing is pending).
+    pub fn flush_batch(&mut self) {
+        if self.batch_count.get() > 0 {
+            self.commit_batch();
         }
     }
 
     pub fn serialize(&self) {
-        let py_data: Vec<Vec<Vec<f32>>> = self.neurons.iter().map(|n| n.to_serializable()).collect();
+        let py_data = self.neurons.to_serializable_all();
 
         let pickle = serde_pickle::to_vec(&py_data, true).unwrap();
 
@@ -221,14 +847,145 @@ impl CompAENetwork {
 
         file.write_all(&pickle).unwrap();
     }
+
+    /// Writes a versioned checkpoint that `load` can read back into a fully
+    /// working network — including the optimizer/regularizer/batch config,
+    /// not just the weights — for inference or resumed training. Only
+    /// supports `Dense` (fully connected) networks — panics on a `new_conv`
+    /// network rather than writing a checkpoint that `load` would silently
+    /// misinterpret; `new_conv` networks can still `serialize()` their
+    /// kernels for the pickle-based visualization.
+    pub fn save(&self, path: &Path) {
+        let neurons = match &self.neurons {
+            NeuronBank::Dense(neurons) => neurons,
+            NeuronBank::Conv(_) => panic!(
+                "CompAENetwork::save only supports Dense networks; a new_conv network's \
+                 kernel_size^2 weights would round-trip through `load` as a corrupt Dense \
+                 network (kernel_area weights zipped against side*side inputs). Use \
+                 `serialize()` to export conv kernels for the pickle-based visualization."
+            ),
+        };
+
+        let serialized = SerializedNetwork {
+            version: NETWORK_FORMAT_VERSION,
+            learning_constant: self.learning_constant,
+            use_adam: self.use_adam,
+            weight_decay: self.weight_decay,
+            max_norm: self.max_norm,
+            init_scheme: self.init_scheme,
+            batch_size: self.batch_size,
+            samples_per_epoch: self.samples_per_epoch,
+            num_neurons: neurons.len(),
+            num_inputs: self.inputs.len(),
+            weights: neurons.iter().map(|n| n.get_weights()).collect(),
+        };
+
+        let json = serde_json::to_string(&serialized).unwrap();
+        let mut file = File::create(path).unwrap();
+        file.write_all(json.as_bytes()).unwrap();
+    }
+
+    /// Loads a checkpoint written by `save` into a network ready to run
+    /// `run_inference` or resume training via `perform_adjustment`, with the
+    /// original run's optimizer/regularizer/batch config restored (the Adam
+    /// moment estimates and mini-batch timestep aren't persisted, so Adam's
+    /// bias correction restarts from `t = 0` on the first post-load step).
+    pub fn load(path: &Path) -> CompAENetwork {
+        let mut contents = String::new();
+        File::open(path).unwrap().read_to_string(&mut contents).unwrap();
+
+        let serialized: SerializedNetwork = serde_json::from_str(&contents).unwrap();
+        assert!(
+            !(serialized.use_adam && serialized.batch_size > 1),
+            "CompAENetwork::load: checkpoint combines use_adam and batch_size > 1, which isn't supported"
+        );
+
+        let weight_holder = Rc::new(WeightHolder::new());
+
+        let inputs = (0..serialized.num_inputs)
+            .map(|_| Rc::new(NeuronicInput::new(Rc::clone(&weight_holder))))
+            .collect::<Vec<Rc<NeuronicInput>>>();
+
+        let neurons = serialized
+            .weights
+            .into_iter()
+            .enumerate()
+            .map(|(i, weights)| {
+                Rc::new(CompAENeuron::from_weights(
+                    i.to_string(),
+                    serialized.learning_constant,
+                    serialized.use_adam,
+                    serialized.weight_decay,
+                    serialized.max_norm,
+                    weights,
+                    inputs.iter().map(|input| Rc::clone(input)).collect(),
+                    Rc::clone(&weight_holder),
+                ))
+            })
+            .collect::<Vec<Rc<CompAENeuron>>>();
+
+        CompAENetwork {
+            learning_constant: serialized.learning_constant,
+            use_adam: serialized.use_adam,
+            weight_decay: serialized.weight_decay,
+            max_norm: serialized.max_norm,
+            init_scheme: serialized.init_scheme,
+            neurons: NeuronBank::Dense(neurons),
+            inputs,
+            weight_holder,
+            t: Cell::new(0),
+            batch_size: serialized.batch_size,
+            batch_count: Cell::new(0),
+            samples_per_epoch: serialized.samples_per_epoch,
+            epoch_sample_count: Cell::new(0),
+        }
+    }
+
+    /// Alias for `load`, matching the name used elsewhere for the inverse of
+    /// `serialize`.
+    pub fn deserialize(path: &Path) -> CompAENetwork {
+        Self::load(path)
+    }
+
+    /// Runs a single input through the network and reports each neuron's `em`,
+    /// the winning neuron, and the reconstructed input, without adjusting weights.
+    pub fn run_inference(&self, input: &[f32]) -> InferenceResult {
+        self.weight_holder.clear();
+
+        for (neuronic_input, val) in self.inputs.iter().zip(input.iter()) {
+            neuronic_input.load_input_measure(*val);
+            neuronic_input.clear_total_weighted_prediction();
+        }
+
+        self.neurons.run_prediction_phase();
+
+        let neuron_ems = self.neurons.get_current_ems();
+
+        // A neuron whose weights have all been clamped to zero produces a
+        // NaN `em` (`compute_em` divides by `total_weight.sqrt()`); skip
+        // those rather than letting `partial_cmp` panic on an unorderable
+        // pair, and fall back to neuron 0 if every neuron is degenerate.
+        let winning_neuron = neuron_ems
+            .iter()
+            .enumerate()
+            .filter(|(_, em)| !em.is_nan())
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        let reconstruction = self.inputs.iter().map(|input| input.get_reconstruction()).collect();
+
+        InferenceResult {
+            reconstruction,
+            neuron_ems,
+            winning_neuron,
+        }
+    }
 }
 
 impl MnistNetwork for CompAENetwork {
     fn get_neurons(&self) -> Vec<Rc<dyn MnistNeuron>> {
-        self.neurons
-            .iter()
-            .map(|neuron| Rc::clone(neuron) as Rc<dyn MnistNeuron>)
-            .collect()
+        self.neurons.mnist_neurons()
     }
 
     /// Clears the weight holder if it loads (0, 0) because
@@ -247,18 +1004,172 @@ impl MnistNetwork for CompAENetwork {
 
     fn perform_adjustment(&mut self) {
         // Reconstruction phase
-        for neuron in self.neurons.iter_mut() {
-            neuron.run_prediction_phase();
-        }
+        self.neurons.run_prediction_phase();
 
         // Cache the reconstruction error for speedy lookup
         for input in &self.inputs {
             input.cache_reconstruction_error();
         }
 
-        // Run learning phase
-        for neuron in self.neurons.iter_mut() {
-            neuron.run_learning_phase();
+        // Accumulate this sample's contribution to the current mini-batch
+        self.t.replace(self.t.get() + 1);
+        self.neurons.run_learning_phase(self.t.get());
+
+        self.batch_count.replace(self.batch_count.get() + 1);
+        if self.batch_count.get() >= self.batch_size {
+            self.commit_batch();
+        }
+
+        // `load_val`/`perform_adjustment` are only driven over training
+        // samples, so counting calls here lets us detect the epoch boundary
+        // and flush any still-pending partial batch ourselves, with no
+        // per-epoch hook (or repeated `take_metric` call) needed from main.
+        if self.samples_per_epoch > 0 {
+            self.epoch_sample_count.replace(self.epoch_sample_count.get() + 1);
+
+            if self.epoch_sample_count.get() >= self.samples_per_epoch {
+                self.epoch_sample_count.replace(0);
+                self.flush_batch();
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_load_round_trip_restores_weights_and_config() {
+        let network = CompAENetwork::new(
+            0.01,
+            3,
+            4,
+            false,
+            0.001,
+            Some(2.0),
+            InitScheme::Uniform { min: 0.0, max: 0.5 },
+            2,
+            4,
+        );
+
+        let path = std::env::temp_dir().join(format!("comp_ae_test_checkpoint_{}.json", std::process::id()));
+        network.save(&path);
+        let loaded = CompAENetwork::load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(network.neurons.get_weights_all(), loaded.neurons.get_weights_all());
+        assert_eq!(network.learning_constant, loaded.learning_constant);
+        assert_eq!(network.use_adam, loaded.use_adam);
+        assert_eq!(network.weight_decay, loaded.weight_decay);
+        assert_eq!(network.max_norm, loaded.max_norm);
+        assert_eq!(network.batch_size, loaded.batch_size);
+        assert_eq!(network.samples_per_epoch, loaded.samples_per_epoch);
+        assert!(matches!(loaded.init_scheme, InitScheme::Uniform { min, max } if min == 0.0 && max == 0.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn save_rejects_conv_network() {
+        let network = CompAENetwork::new_conv(
+            0.01,
+            2,
+            3,
+            1,
+            4,
+            InitScheme::Uniform { min: 0.0, max: 0.5 },
+        );
+
+        let path = std::env::temp_dir().join(format!("comp_ae_test_conv_checkpoint_{}.json", std::process::id()));
+        network.save(&path);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_rejects_adam_with_batch_size_greater_than_one() {
+        CompAENetwork::new(0.01, 1, 2, true, 0.0, None, InitScheme::Uniform { min: 0.0, max: 0.5 }, 2, 0);
+    }
+
+    /// Hand-computes the first Adam step (t = 1) and checks it against
+    /// `accumulate_adam`'s bias-corrected update.
+    #[test]
+    fn accumulate_adam_matches_bias_corrected_formula() {
+        let weight_holder = Rc::new(WeightHolder::new());
+        let input = Rc::new(NeuronicInput::new(Rc::clone(&weight_holder)));
+        input.current_reconstruction_error.replace(0.5);
+
+        let neuron = CompAENeuron::new(
+            "0".to_string(),
+            1.0,
+            true,
+            0.0,
+            None,
+            InitScheme::Uniform { min: 0.0, max: 0.5 },
+            1,
+            vec![Rc::clone(&input)],
+            Rc::clone(&weight_holder),
+        );
+        neuron.current_em.replace(1.0);
+
+        neuron.accumulate_adam(1);
+
+        // g = reconstruction_error * (current_em / total_weight) = 0.5 * 1.0
+        let g = 0.5_f32;
+        let m_hat = (ADAM_BETA1 * 0.0 + (1.0 - ADAM_BETA1) * g) / (1.0 - ADAM_BETA1.powi(1));
+        let v_hat = (ADAM_BETA2 * 0.0 + (1.0 - ADAM_BETA2) * g * g) / (1.0 - ADAM_BETA2.powi(1));
+        let expected_delta = 1.0 * m_hat / (v_hat.sqrt() + ADAM_EPSILON);
+
+        assert!((neuron.pending_deltas[0].get() - expected_delta).abs() < 1e-6);
+    }
+}
+
+#[cfg(test)]
+mod conv_tests {
+    use super::*;
+
+    fn make_filter(side: usize, kernel_size: usize, stride: usize) -> ConvFilter {
+        let weight_holder = Rc::new(WeightHolder::new());
+        let inputs = (0..side * side)
+            .map(|_| Rc::new(NeuronicInput::new(Rc::clone(&weight_holder))))
+            .collect::<Vec<Rc<NeuronicInput>>>();
+
+        ConvFilter::new(
+            "0".to_string(),
+            0.01,
+            kernel_size,
+            stride,
+            side,
+            InitScheme::Uniform { min: 0.0, max: 0.5 },
+            1,
+            inputs,
+            weight_holder,
+        )
+    }
+
+    #[test]
+    fn output_side_accounts_for_kernel_and_stride() {
+        let filter = make_filter(4, 2, 1);
+        assert_eq!(filter.output_side(), 3);
+
+        let filter = make_filter(4, 2, 2);
+        assert_eq!(filter.output_side(), 2);
+    }
+
+    #[test]
+    fn window_indices_cover_the_right_receptive_field() {
+        let filter = make_filter(4, 2, 1);
+
+        assert_eq!(filter.window_indices(0, 0), vec![0, 1, 4, 5]);
+        assert_eq!(filter.window_indices(1, 1), vec![5, 6, 9, 10]);
+    }
+
+    #[test]
+    fn overlap_counts_match_window_coverage() {
+        let filter = make_filter(4, 2, 1);
+
+        // Pixel 0 (top-left corner) is covered only by window (0, 0)
+        assert_eq!(filter.overlap_counts[0], 1.0);
+        // Pixel 5 sits under all four windows whose receptive fields touch it
+        assert_eq!(filter.overlap_counts[5], 4.0);
+    }
+}